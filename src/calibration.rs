@@ -0,0 +1,153 @@
+use crate::message_types::{CalibrationPhase, LssDriverError};
+use crate::{LSSDriver, LssCommand};
+use std::future::Future;
+use std::time::Duration;
+use tokio::sync::watch;
+
+/// How many consecutive in-tolerance samples count as "settled".
+const REQUIRED_STABLE_SAMPLES: usize = 3;
+/// Delay between position samples while settling.
+const SAMPLE_INTERVAL: Duration = Duration::from_millis(50);
+
+impl LSSDriver {
+    /// Write an origin offset (in degrees) to the servo's config.
+    ///
+    /// The offset shifts the servo's notion of zero and is stored with the `CO`
+    /// config command, matching the tenths-of-a-degree units used by the
+    /// position commands.
+    pub async fn set_origin_offset(
+        &mut self,
+        id: u8,
+        offset: f32,
+    ) -> Result<(), LssDriverError> {
+        let value = (offset * 10.0).round() as i32;
+        self.driver
+            .send(LssCommand::with_param(id, "CO", value))
+            .await?;
+        Ok(())
+    }
+
+    /// Calibrate a servo's origin offset as an awaitable state machine.
+    ///
+    /// The returned future commands the servo limp, samples `read_position`
+    /// until the readings stabilize within `tolerance` degrees, computes the
+    /// offset from nominal zero and writes it to the servo's origin-offset
+    /// config. The whole run is bounded by `timeout`; if the position never
+    /// settles the future resolves to [`LssDriverError::TimeoutError`].
+    ///
+    /// The returned [`watch::Receiver`] reports the current
+    /// [`CalibrationPhase`] so callers polling the future can surface progress.
+    pub fn calibrate_origin(
+        &mut self,
+        id: u8,
+        tolerance: f32,
+        timeout: Duration,
+    ) -> (
+        watch::Receiver<CalibrationPhase>,
+        impl Future<Output = Result<f32, LssDriverError>> + '_,
+    ) {
+        let (phase_tx, phase_rx) = watch::channel(CalibrationPhase::Starting);
+        let run = async move {
+            match tokio::time::timeout(
+                timeout,
+                self.run_calibration(id, tolerance, &phase_tx),
+            )
+            .await
+            {
+                Ok(result) => result,
+                Err(_) => Err(LssDriverError::TimeoutError),
+            }
+        };
+        (phase_rx, run)
+    }
+
+    async fn run_calibration(
+        &mut self,
+        id: u8,
+        tolerance: f32,
+        phase: &watch::Sender<CalibrationPhase>,
+    ) -> Result<f32, LssDriverError> {
+        let _ = phase.send(CalibrationPhase::Starting);
+        self.limp(id).await?;
+
+        let _ = phase.send(CalibrationPhase::Settling);
+        let mut last = self.read_position(id).await?;
+        let mut stable = 0;
+        while stable < REQUIRED_STABLE_SAMPLES {
+            tokio::time::sleep(SAMPLE_INTERVAL).await;
+            let current = self.read_position(id).await?;
+            if (current - last).abs() <= tolerance {
+                stable += 1;
+            } else {
+                stable = 0;
+            }
+            last = current;
+        }
+
+        let _ = phase.send(CalibrationPhase::Writing);
+        // Offset that brings the settled reading back to nominal zero.
+        let offset = -last;
+        self.set_origin_offset(id, offset).await?;
+
+        let _ = phase.send(CalibrationPhase::Done);
+        Ok(offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FramedDriver, LssResponse};
+    use async_trait::async_trait;
+
+    /// Mock transport whose reported position either stays put (settles) or
+    /// drifts by ten degrees on every sample (never settles).
+    struct MockDriver {
+        jitter: bool,
+        counter: i32,
+    }
+
+    #[async_trait]
+    impl FramedDriver for MockDriver {
+        async fn send(&mut self, _command: LssCommand) -> Result<(), LssDriverError> {
+            Ok(())
+        }
+
+        async fn send_with_response(
+            &mut self,
+            command: LssCommand,
+        ) -> Result<LssResponse, LssDriverError> {
+            // Position is reported in tenths of a degree.
+            let tenths = if self.jitter {
+                self.counter += 100;
+                self.counter
+            } else {
+                0
+            };
+            Ok(LssResponse::new(format!("*{}QD{}", command.id(), tenths)))
+        }
+    }
+
+    #[tokio::test]
+    async fn calibration_settles_and_reports_done() {
+        let mut driver = LSSDriver::with_driver(Box::new(MockDriver {
+            jitter: false,
+            counter: 0,
+        }));
+        let (phase, run) = driver.calibrate_origin(5, 1.0, Duration::from_secs(5));
+        let offset = run.await.unwrap();
+        assert_eq!(offset, 0.0);
+        assert_eq!(*phase.borrow(), CalibrationPhase::Done);
+    }
+
+    #[tokio::test]
+    async fn calibration_times_out_when_position_never_settles() {
+        let mut driver = LSSDriver::with_driver(Box::new(MockDriver {
+            jitter: true,
+            counter: 0,
+        }));
+        let (_phase, run) = driver.calibrate_origin(5, 1.0, Duration::from_millis(120));
+        let result = run.await;
+        assert!(matches!(result, Err(LssDriverError::TimeoutError)));
+    }
+}
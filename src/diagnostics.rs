@@ -0,0 +1,37 @@
+use crate::message_types::{Diagnostics, DiagnosticsConfig, LssDriverError};
+use crate::LSSDriver;
+
+impl LSSDriver {
+    /// Read a servo's full health and return a single severity-ranked verdict.
+    ///
+    /// Uses the default temperature thresholds; see
+    /// [`query_diagnostics_with_config`](Self::query_diagnostics_with_config) to
+    /// supply your own warn/error bounds.
+    pub async fn query_diagnostics(&mut self, id: u8) -> Result<Diagnostics, LssDriverError> {
+        self.query_diagnostics_with_config(id, &DiagnosticsConfig::default())
+            .await
+    }
+
+    /// Like [`query_diagnostics`](Self::query_diagnostics) but with configurable
+    /// warn/error temperature bounds.
+    pub async fn query_diagnostics_with_config(
+        &mut self,
+        id: u8,
+        config: &DiagnosticsConfig,
+    ) -> Result<Diagnostics, LssDriverError> {
+        let voltage = self.read_voltage(id).await?;
+        let temperature = self.read_temperature(id).await?;
+        let current = self.read_current(id).await?;
+        let status = self.query_status(id).await?;
+        let safe_mode = self.query_safety_status(id).await?;
+        Ok(Diagnostics::evaluate(
+            id,
+            voltage,
+            temperature,
+            current,
+            status,
+            safe_mode,
+            config,
+        ))
+    }
+}
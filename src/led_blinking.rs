@@ -0,0 +1,36 @@
+use crate::message_types::{LedBlinking, LssDriverError};
+use crate::{LSSDriver, LssCommand};
+
+impl LSSDriver {
+    /// Configure which motor states make the servo's LED blink.
+    ///
+    /// The flags are folded into the bitmask expected by the `CLB` config
+    /// command. Passing an empty slice (or `LedBlinking::NoBlinking`) disables
+    /// blinking.
+    pub async fn set_led_blinking(
+        &mut self,
+        id: u8,
+        blinking: &[LedBlinking],
+    ) -> Result<(), LssDriverError> {
+        let value = LedBlinking::to_config_value(blinking);
+        self.driver
+            .send(LssCommand::with_param(id, "CLB", value))
+            .await?;
+        Ok(())
+    }
+
+    /// Read back which motor states currently trigger LED blinking.
+    ///
+    /// Decomposes the returned bitmask into the set of active flags.
+    pub async fn read_led_blinking(
+        &mut self,
+        id: u8,
+    ) -> Result<Vec<LedBlinking>, LssDriverError> {
+        let response = self
+            .driver
+            .send_with_response(LssCommand::simple(id, "QLB"))
+            .await?;
+        let value = response.get_val("QLB")?;
+        LedBlinking::from_i32(value)
+    }
+}
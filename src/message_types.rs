@@ -14,6 +14,9 @@ pub enum LssDriverError {
     FailedOpeningSerialPort,
     #[error("Failed to open serial port")]
     SendingError,
+    #[error("Servo id {0} is not part of this group")]
+    /// Error triggered when a group command references an ID outside the group
+    ServoNotInGroup(u8),
 }
 
 /// Colors for the LED on the servo
@@ -158,6 +161,206 @@ pub enum LedBlinking {
     AlwaysBlink = 63,
 }
 
+impl LedBlinking {
+    /// Fold a slice of blinking flags into the single integer expected by the
+    /// servo's `CLB` config command.
+    /// `NoBlinking` contributes nothing and `AlwaysBlink` covers every flag.
+    pub(crate) fn to_config_value(blinking: &[LedBlinking]) -> i32 {
+        blinking
+            .iter()
+            .fold(0, |acc, flag| acc | *flag as i32)
+    }
+
+    /// Decompose a returned bitmask back into the set of active flags.
+    /// This is the inverse of [`LedBlinking::to_config_value`], mirroring the
+    /// `from_i32` decoders on `LedColor` and `MotorStatus`.
+    pub(crate) fn from_i32(number: i32) -> Result<Vec<LedBlinking>, LssDriverError> {
+        if number == 0 {
+            return Ok(vec![LedBlinking::NoBlinking]);
+        }
+        if number < 0 || number > LedBlinking::AlwaysBlink as i32 {
+            return Err(LssDriverError::PacketParsingError(format!(
+                "Failed parsing LedBlinking from {}",
+                number
+            )));
+        }
+        // `AlwaysBlink` is its own value covering every bit, so keep it whole
+        // instead of expanding into the individual flags; this makes it the
+        // exact inverse of `to_config_value(&[LedBlinking::AlwaysBlink])`.
+        if number == LedBlinking::AlwaysBlink as i32 {
+            return Ok(vec![LedBlinking::AlwaysBlink]);
+        }
+        let flags = [
+            LedBlinking::Limp,
+            LedBlinking::Holding,
+            LedBlinking::Accelerating,
+            LedBlinking::Decelerating,
+            LedBlinking::Free,
+            LedBlinking::Travelling,
+        ]
+        .iter()
+        .copied()
+        .filter(|flag| number & *flag as i32 != 0)
+        .collect();
+        Ok(flags)
+    }
+}
+
+/// Combined snapshot of a single servo as produced by the background
+/// telemetry task started with `LSSDriver::start_telemetry`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatusReport {
+    /// ID of the servo this report describes
+    pub id: u8,
+    /// Input voltage in volts
+    pub voltage: f32,
+    /// Temperature in degrees Celsius
+    pub temperature: f32,
+    /// Current draw in amperes
+    pub current: f32,
+    /// Position in degrees
+    pub position: f32,
+    /// Motor status as reported by `query_status`
+    pub status: MotorStatus,
+    /// Reason the servo is in safe mode (if any)
+    pub safe_mode: SafeModeStatus,
+}
+
+/// Phase of an origin calibration run.
+///
+/// Callers watching a `calibrate_origin` run observe these transitions in order
+/// so they can surface progress, the same way a sensor calibration future moves
+/// through start/waiting/finished phases.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CalibrationPhase {
+    /// Commanding the servo limp and preparing to sample
+    Starting,
+    /// Waiting for the sampled position to settle within tolerance
+    Settling,
+    /// Writing the computed offset to the servo's origin-offset config
+    Writing,
+    /// Calibration finished successfully
+    Done,
+}
+
+/// Severity of a servo's health as computed by [`Diagnostics`].
+///
+/// Ordered so the worst condition seen across sub-checks wins.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// Everything nominal
+    Ok,
+    /// Approaching a limit but still operating
+    Warn,
+    /// A limit has been hit or the motor is faulted
+    Error,
+}
+
+/// Temperature thresholds used when grading a servo's health.
+///
+/// Defaults approximate the LSS safe-mode temperature limit.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DiagnosticsConfig {
+    /// Temperature (°C) at or above which severity escalates to `Warn`
+    pub warn_temperature: f32,
+    /// Temperature (°C) at or above which severity escalates to `Error`
+    pub error_temperature: f32,
+}
+
+impl Default for DiagnosticsConfig {
+    fn default() -> DiagnosticsConfig {
+        DiagnosticsConfig {
+            warn_temperature: 70.0,
+            error_temperature: 80.0,
+        }
+    }
+}
+
+/// One actionable verdict for a servo plus the underlying readings.
+///
+/// Produced by `LSSDriver::query_diagnostics`, which reads the individual
+/// fields and then merges them into a single severity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostics {
+    /// ID of the servo this verdict describes
+    pub id: u8,
+    /// Input voltage in volts
+    pub voltage: f32,
+    /// Temperature in degrees Celsius
+    pub temperature: f32,
+    /// Current draw in amperes
+    pub current: f32,
+    /// Motor status as reported by `query_status`
+    pub status: MotorStatus,
+    /// Reason the servo is in safe mode (if any)
+    pub safe_mode: SafeModeStatus,
+    /// Merged severity across all sub-checks
+    pub severity: Severity,
+    /// Message explaining the condition that set the final severity
+    pub message: String,
+}
+
+impl Diagnostics {
+    /// Grade a set of readings into a single verdict.
+    ///
+    /// The merge rule is "take the max severity seen across all sub-checks,
+    /// keeping the first message that set the final level", mirroring how
+    /// diagnostic aggregators combine component statuses.
+    pub(crate) fn evaluate(
+        id: u8,
+        voltage: f32,
+        temperature: f32,
+        current: f32,
+        status: MotorStatus,
+        safe_mode: SafeModeStatus,
+        config: &DiagnosticsConfig,
+    ) -> Diagnostics {
+        let mut severity = Severity::Ok;
+        let mut message = String::from("Servo healthy");
+
+        let mut escalate = |level: Severity, reason: &str| {
+            if level > severity {
+                severity = level;
+                message = reason.to_owned();
+            }
+        };
+
+        match safe_mode {
+            SafeModeStatus::CurrentLimit => escalate(Severity::Error, "Safe mode: current limit"),
+            SafeModeStatus::InputVoltageOutOfRange => {
+                escalate(Severity::Error, "Safe mode: input voltage out of range")
+            }
+            SafeModeStatus::TemperatureLimit => {
+                escalate(Severity::Error, "Safe mode: temperature limit")
+            }
+            SafeModeStatus::NoLimits => {}
+        }
+
+        match status {
+            MotorStatus::Stuck => escalate(Severity::Error, "Motor stuck"),
+            MotorStatus::Blocked => escalate(Severity::Error, "Motor blocked"),
+            _ => {}
+        }
+
+        if temperature >= config.error_temperature {
+            escalate(Severity::Error, "Temperature above error threshold");
+        } else if temperature >= config.warn_temperature {
+            escalate(Severity::Warn, "Temperature approaching limit");
+        }
+
+        Diagnostics {
+            id,
+            voltage,
+            temperature,
+            current,
+            status,
+            safe_mode,
+            severity,
+            message,
+        }
+    }
+}
+
 /// Modifiers used for some commands
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CommandModifier {
@@ -249,6 +452,92 @@ mod tests {
         }
     }
 
+    #[test]
+    fn led_blinking_folds_into_bitmask() {
+        let value = LedBlinking::to_config_value(&[LedBlinking::Limp, LedBlinking::Holding]);
+        assert_eq!(value, 3);
+    }
+
+    #[test]
+    fn led_blinking_empty_folds_to_zero() {
+        assert_eq!(LedBlinking::to_config_value(&[]), 0);
+    }
+
+    #[test]
+    fn led_blinking_parse_fails() {
+        let flags = LedBlinking::from_i32(64);
+        assert!(flags.is_err());
+    }
+
+    #[test]
+    fn led_blinking_round_trips() {
+        let flags = vec![LedBlinking::Accelerating, LedBlinking::Travelling];
+        let value = LedBlinking::to_config_value(&flags);
+        assert_eq!(LedBlinking::from_i32(value).unwrap(), flags);
+    }
+
+    #[test]
+    fn led_blinking_always_blink_round_trips() {
+        let value = LedBlinking::to_config_value(&[LedBlinking::AlwaysBlink]);
+        assert_eq!(value, 63);
+        assert_eq!(
+            LedBlinking::from_i32(value).unwrap(),
+            vec![LedBlinking::AlwaysBlink]
+        );
+    }
+
+    #[test]
+    fn led_blinking_zero_parses_to_no_blinking() {
+        assert_eq!(
+            LedBlinking::from_i32(0).unwrap(),
+            vec![LedBlinking::NoBlinking]
+        );
+    }
+
+    #[test]
+    fn diagnostics_healthy_is_ok() {
+        let diag = Diagnostics::evaluate(
+            5,
+            11.5,
+            40.0,
+            0.2,
+            MotorStatus::Holding,
+            SafeModeStatus::NoLimits,
+            &DiagnosticsConfig::default(),
+        );
+        assert_eq!(diag.severity, Severity::Ok);
+    }
+
+    #[test]
+    fn diagnostics_warns_near_temperature_limit() {
+        let diag = Diagnostics::evaluate(
+            5,
+            11.5,
+            72.0,
+            0.2,
+            MotorStatus::Holding,
+            SafeModeStatus::NoLimits,
+            &DiagnosticsConfig::default(),
+        );
+        assert_eq!(diag.severity, Severity::Warn);
+    }
+
+    #[test]
+    fn diagnostics_keeps_first_message_at_final_level() {
+        // Both safe mode and a stuck motor are Errors; the first check wins.
+        let diag = Diagnostics::evaluate(
+            5,
+            11.5,
+            90.0,
+            2.0,
+            MotorStatus::Stuck,
+            SafeModeStatus::CurrentLimit,
+            &DiagnosticsConfig::default(),
+        );
+        assert_eq!(diag.severity, Severity::Error);
+        assert_eq!(diag.message, "Safe mode: current limit");
+    }
+
     #[test]
     fn motor_status_parse_fails() {
         let status = MotorStatus::from_i32(42);
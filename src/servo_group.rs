@@ -0,0 +1,119 @@
+use crate::message_types::LssDriverError;
+use crate::LSSDriver;
+
+/// A set of servo IDs driven together.
+///
+/// Multi-joint arms and legs otherwise scatter `move_to_position` calls across
+/// the caller's own loops. A `ServoGroup` keeps the IDs in one place and applies
+/// per-servo setpoints and group reads from a single call.
+///
+/// Note that this is a convenience wrapper, not a pipelining optimisation: the
+/// LSS bus is a half-duplex serial line where each servo replies to its own
+/// query, so queries cannot be fired ahead and their responses collected in
+/// bulk — commands travel the link one at a time exactly as a hand-written loop
+/// would. The value here is keeping the IDs and per-ID error handling in one
+/// place, not saving round-trips.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServoGroup {
+    ids: Vec<u8>,
+}
+
+impl ServoGroup {
+    /// Create a group from a set of servo IDs.
+    pub fn new(ids: &[u8]) -> ServoGroup {
+        ServoGroup { ids: ids.to_vec() }
+    }
+
+    /// IDs belonging to this group.
+    pub fn ids(&self) -> &[u8] {
+        &self.ids
+    }
+
+    /// Move every listed servo to its matching angle in degrees.
+    ///
+    /// Setpoints are sent one after another. A setpoint whose ID is not part of
+    /// the group is rejected with [`LssDriverError::ServoNotInGroup`] before any
+    /// command is sent, so a typo'd ID can't masquerade as a successful no-op.
+    pub async fn move_all(
+        &self,
+        driver: &mut LSSDriver,
+        setpoints: &[(u8, f32)],
+    ) -> Result<(), LssDriverError> {
+        for (id, _) in setpoints {
+            if !self.ids.contains(id) {
+                return Err(LssDriverError::ServoNotInGroup(*id));
+            }
+        }
+        for (id, angle) in setpoints {
+            driver.move_to_position(*id, *angle).await?;
+        }
+        Ok(())
+    }
+
+    /// Read the current position of every servo in the group.
+    ///
+    /// Servos are queried in turn over the shared link; the result holds one
+    /// entry per ID so a single unresponsive servo reports its error in place
+    /// rather than poisoning the rest of the results.
+    pub async fn read_positions(
+        &self,
+        driver: &mut LSSDriver,
+    ) -> Vec<(u8, Result<f32, LssDriverError>)> {
+        let mut results = Vec::with_capacity(self.ids.len());
+        for id in &self.ids {
+            results.push((*id, driver.read_position(*id).await));
+        }
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FramedDriver, LssCommand, LssResponse};
+    use async_trait::async_trait;
+
+    /// Mock transport that answers position queries with a fixed value and
+    /// fails for a configured set of "dead" servo IDs.
+    struct MockDriver {
+        dead: Vec<u8>,
+    }
+
+    #[async_trait]
+    impl FramedDriver for MockDriver {
+        async fn send(&mut self, _command: LssCommand) -> Result<(), LssDriverError> {
+            Ok(())
+        }
+
+        async fn send_with_response(
+            &mut self,
+            command: LssCommand,
+        ) -> Result<LssResponse, LssDriverError> {
+            let id = command.id();
+            if self.dead.contains(&id) {
+                return Err(LssDriverError::TimeoutError);
+            }
+            Ok(LssResponse::new(format!("*{}QD{}", id, 100)))
+        }
+    }
+
+    #[tokio::test]
+    async fn read_positions_isolates_per_servo_errors() {
+        let mut driver = LSSDriver::with_driver(Box::new(MockDriver { dead: vec![6] }));
+        let group = ServoGroup::new(&[5, 6, 7]);
+        let results = group.read_positions(&mut driver).await;
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].0, 5);
+        assert!(results[0].1.is_ok());
+        assert!(results[1].1.is_err());
+        assert!(results[2].1.is_ok());
+    }
+
+    #[tokio::test]
+    async fn move_all_rejects_unknown_ids() {
+        let mut driver = LSSDriver::with_driver(Box::new(MockDriver { dead: vec![] }));
+        let group = ServoGroup::new(&[5, 6]);
+        let result = group.move_all(&mut driver, &[(5, 10.0), (9, 20.0)]).await;
+        assert!(matches!(result, Err(LssDriverError::ServoNotInGroup(9))));
+    }
+}
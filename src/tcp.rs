@@ -0,0 +1,159 @@
+use crate::message_types::LssDriverError;
+use crate::{FramedDriver, LSSDriver, LssCommand, LssResponse};
+use async_trait::async_trait;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpStream, ToSocketAddrs};
+
+/// Byte that terminates every LSS ASCII packet.
+const FRAME_TERMINATOR: u8 = b'\r';
+/// How long to wait for each byte of a response before giving up.
+const READ_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// [`FramedDriver`] that carries LSS ASCII packets over a TCP connection.
+///
+/// This targets a serial-over-TCP forwarder running next to the servo bus (for
+/// example a tiny bridge on a Raspberry Pi), letting an `LSSDriver` on a
+/// separate control machine talk to the servos as if they were local.
+pub struct TcpDriver<A: ToSocketAddrs + Clone + Send + Sync> {
+    addr: A,
+    stream: Option<TcpStream>,
+}
+
+impl<A: ToSocketAddrs + Clone + Send + Sync> TcpDriver<A> {
+    /// Connect to the remote bridge at `addr`.
+    pub async fn connect(addr: A) -> Result<TcpDriver<A>, LssDriverError> {
+        let stream = TcpStream::connect(addr.clone())
+            .await
+            .map_err(|_| LssDriverError::SendingError)?;
+        Ok(TcpDriver {
+            addr,
+            stream: Some(stream),
+        })
+    }
+
+    /// Return the live stream, reconnecting once if the socket has been lost.
+    async fn stream(&mut self) -> Result<&mut TcpStream, LssDriverError> {
+        if self.stream.is_none() {
+            let stream = TcpStream::connect(self.addr.clone())
+                .await
+                .map_err(|_| LssDriverError::SendingError)?;
+            self.stream = Some(stream);
+        }
+        Ok(self.stream.as_mut().expect("stream just set"))
+    }
+}
+
+#[async_trait]
+impl<A: ToSocketAddrs + Clone + Send + Sync> FramedDriver for TcpDriver<A> {
+    async fn send(&mut self, command: LssCommand) -> Result<(), LssDriverError> {
+        let stream = self.stream().await?;
+        if stream.write_all(command.as_bytes()).await.is_err() {
+            // Drop the socket so the next call reconnects.
+            self.stream = None;
+            return Err(LssDriverError::SendingError);
+        }
+        Ok(())
+    }
+
+    async fn send_with_response(
+        &mut self,
+        command: LssCommand,
+    ) -> Result<LssResponse, LssDriverError> {
+        self.send(command).await?;
+        let stream = self.stream().await?;
+        let mut buffer = Vec::new();
+        let mut byte = [0u8; 1];
+        loop {
+            // A bridge that accepts the connection but never replies would
+            // otherwise block forever, so bound every read.
+            let read = tokio::time::timeout(READ_TIMEOUT, stream.read(&mut byte)).await;
+            match read {
+                Ok(Ok(0)) => {
+                    // Remote closed mid-response.
+                    self.stream = None;
+                    return Err(LssDriverError::TimeoutError);
+                }
+                Ok(Ok(_)) => {
+                    if byte[0] == FRAME_TERMINATOR {
+                        break;
+                    }
+                    buffer.push(byte[0]);
+                }
+                Ok(Err(_)) => {
+                    self.stream = None;
+                    return Err(LssDriverError::TimeoutError);
+                }
+                Err(_) => {
+                    // Timed out waiting for the peer.
+                    self.stream = None;
+                    return Err(LssDriverError::TimeoutError);
+                }
+            }
+        }
+        let text = String::from_utf8(buffer)
+            .map_err(|err| LssDriverError::PacketParsingError(err.to_string()))?;
+        Ok(LssResponse::new(text))
+    }
+}
+
+impl LSSDriver {
+    /// Build a driver that talks to servos over a TCP serial bridge.
+    ///
+    /// Connects to `addr`, framing the same LSS ASCII packets over the socket.
+    /// Connection and read failures map to
+    /// [`LssDriverError::SendingError`]/[`LssDriverError::TimeoutError`], and the
+    /// transport transparently reconnects on the next command after a drop.
+    pub async fn with_tcp<A: ToSocketAddrs + Clone + Send + Sync + 'static>(
+        addr: A,
+    ) -> Result<LSSDriver, LssDriverError> {
+        let driver = TcpDriver::connect(addr).await?;
+        Ok(LSSDriver::with_driver(Box::new(driver)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncWriteExt as _;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn reads_up_to_the_frame_terminator() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            // Drain the command, then reply with a single framed packet.
+            let mut scratch = [0u8; 64];
+            let _ = socket.read(&mut scratch).await;
+            socket.write_all(b"*5QV11500\r").await.unwrap();
+            socket.flush().await.unwrap();
+        });
+
+        let mut driver = TcpDriver::connect(addr).await.unwrap();
+        let response = driver
+            .send_with_response(LssCommand::simple(5, "QV"))
+            .await
+            .unwrap();
+        // Terminator is stripped and the payload parses back out.
+        assert_eq!(response.get_val("QV").unwrap(), 11500);
+    }
+
+    #[tokio::test]
+    async fn silent_peer_times_out() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            // Accept but never reply.
+            let (_socket, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(60)).await;
+        });
+
+        let mut driver = TcpDriver::connect(addr).await.unwrap();
+        let result = driver
+            .send_with_response(LssCommand::simple(5, "QV"))
+            .await;
+        assert!(matches!(result, Err(LssDriverError::TimeoutError)));
+    }
+}
@@ -0,0 +1,106 @@
+use crate::message_types::{LssDriverError, StatusReport};
+use crate::LSSDriver;
+use std::time::Duration;
+use tokio::sync::mpsc::{self, Receiver};
+
+impl LSSDriver {
+    /// Start polling a set of servos on a fixed interval in the background.
+    ///
+    /// This mirrors the servo's "active report" mode: instead of every caller
+    /// hand-rolling the polling loop shown in the examples, the driver drains a
+    /// combined [`StatusReport`] for each servo into the returned channel on
+    /// every tick.
+    ///
+    /// If a single servo times out (or otherwise errors) the error is emitted
+    /// on the channel for that ID and polling continues for the rest, so one
+    /// unresponsive servo never aborts the whole stream. The task stops once the
+    /// receiver is dropped.
+    ///
+    /// The driver is moved into the background task because it exclusively owns
+    /// the serial link; dedicate an `LSSDriver` to telemetry and issue commands
+    /// through a separate one if you need to drive the bus concurrently.
+    pub fn start_telemetry(
+        mut self,
+        ids: &[u8],
+        interval: Duration,
+    ) -> Receiver<Result<StatusReport, LssDriverError>> {
+        let (sender, receiver) = mpsc::channel(ids.len().max(1));
+        let ids = ids.to_vec();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                for id in &ids {
+                    let report = self.read_status_report(*id).await;
+                    if sender.send(report).await.is_err() {
+                        // receiver dropped, nothing left to report to
+                        return;
+                    }
+                }
+            }
+        });
+        receiver
+    }
+
+    /// Read every field of a single servo and bundle them into a [`StatusReport`].
+    async fn read_status_report(&mut self, id: u8) -> Result<StatusReport, LssDriverError> {
+        Ok(StatusReport {
+            id,
+            voltage: self.read_voltage(id).await?,
+            temperature: self.read_temperature(id).await?,
+            current: self.read_current(id).await?,
+            position: self.read_position(id).await?,
+            status: self.query_status(id).await?,
+            safe_mode: self.query_safety_status(id).await?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FramedDriver, LssCommand, LssResponse};
+    use async_trait::async_trait;
+
+    /// Mock transport that echoes a zero value for every query and fails every
+    /// query for a configured set of "dead" servo IDs.
+    struct MockDriver {
+        dead: Vec<u8>,
+    }
+
+    #[async_trait]
+    impl FramedDriver for MockDriver {
+        async fn send(&mut self, _command: LssCommand) -> Result<(), LssDriverError> {
+            Ok(())
+        }
+
+        async fn send_with_response(
+            &mut self,
+            command: LssCommand,
+        ) -> Result<LssResponse, LssDriverError> {
+            let id = command.id();
+            if self.dead.contains(&id) {
+                return Err(LssDriverError::TimeoutError);
+            }
+            // Turn "#5QV\r" into the matching "*5QV0" reply.
+            let wire = command.as_bytes();
+            let body: String = wire
+                .iter()
+                .filter(|byte| **byte != b'#' && **byte != b'\r')
+                .map(|byte| *byte as char)
+                .collect();
+            Ok(LssResponse::new(format!("*{}0", body)))
+        }
+    }
+
+    #[tokio::test]
+    async fn telemetry_isolates_per_servo_errors() {
+        let driver = LSSDriver::with_driver(Box::new(MockDriver { dead: vec![6] }));
+        let mut receiver = driver.start_telemetry(&[5, 6], Duration::from_millis(5));
+
+        let first = receiver.recv().await.unwrap();
+        let second = receiver.recv().await.unwrap();
+        assert_eq!(first.unwrap().id, 5);
+        assert!(second.is_err());
+    }
+}